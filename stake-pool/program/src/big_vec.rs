@@ -1,12 +1,22 @@
 //! Big vector type, used with vectors that can't be serde'd
+//!
+//! The `_pod` methods below are a zero-copy alternative to the `Pack`-based
+//! methods: instead of casting a `&[u8]` sub-slice directly to `&T` / `&mut T`
+//! (undefined behavior for any `T` whose alignment is greater than 1, since
+//! account data is only guaranteed to be byte-aligned), they validate the
+//! cast with `bytemuck` first. Types used with the `_pod` methods must be
+//! `#[repr(C)]` and built only out of alignment-1 fields (byte arrays or
+//! little-endian wrapper types), so that the validated cast always succeeds
+//! in practice on a byte-aligned buffer.
 
 use {
     arrayref::array_ref,
     borsh::{BorshDeserialize, BorshSerialize},
+    bytemuck::Pod,
     solana_program::{
         msg, program_error::ProgramError, program_memory::sol_memmove, program_pack::Pack,
     },
-    std::marker::PhantomData,
+    std::{cmp::Ordering, marker::PhantomData, mem::size_of},
 };
 
 /// Contains easy to use utilities for a big vector of Borsh-compatible types,
@@ -165,6 +175,75 @@ impl<'data> BigVec<'data> {
         }
     }
 
+    /// Remove the element matching `element` from an ordered vec, using the
+    /// existing binary search to locate it. Returns whether it was present.
+    pub fn remove_value<T: Pack + Ord>(&mut self, element: &T) -> Result<bool, ProgramError> {
+        let (index, is_found) = self.binary_search(element);
+        if is_found {
+            self.remove::<T>(index)?;
+        }
+        Ok(is_found)
+    }
+
+    /// Binary search an ordered vec by a projection of each element,
+    /// mirroring the signature of the standard library's
+    /// `slice::binary_search_by`. `f` must return the ordering of the
+    /// element it's given relative to the target being searched for.
+    ///
+    /// Returns `Ok(index)` if an element comparing `Equal` was found, or
+    /// `Err(index)` with the index at which it could be inserted to keep
+    /// the vec sorted.
+    pub fn binary_search_by<T: Pack>(&self, f: impl Fn(&T) -> Ordering) -> Result<usize, usize> {
+        let mut min = 0;
+        let mut max = self.len() as usize;
+        while min < max {
+            let mid = min + (max - min) / 2;
+            // unwrap safety: mid is always < len given the loop invariant
+            match f(self.get::<T>(mid).unwrap()) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => min = mid + 1,
+                Ordering::Greater => max = mid,
+            }
+        }
+        Err(min)
+    }
+
+    /// Returns the index of the partition point of an ordered vec according
+    /// to the given predicate, assuming the vec is already partitioned
+    /// according to it: the index of the first element for which the
+    /// predicate returns `false`, or `len` if the predicate is `true` for
+    /// every element.
+    pub fn partition_point<T: Pack>(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.binary_search_by(|elem: &T| {
+            if pred(elem) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+        .unwrap_or_else(|i| i)
+    }
+
+    /// Get an iterator over the sub-window `[start_idx, end_idx)` of the
+    /// vector for the type provided
+    pub fn range<'vec, T: Pack>(
+        &'vec self,
+        start_idx: usize,
+        end_idx: usize,
+    ) -> Result<Iter<'data, 'vec, T>, ProgramError> {
+        let len = self.len() as usize;
+        if start_idx > end_idx || end_idx > len {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        Ok(Iter {
+            len: end_idx,
+            current: start_idx,
+            current_index: VEC_SIZE_BYTES + start_idx * T::LEN,
+            inner: self,
+            phantom: PhantomData,
+        })
+    }
+
     /// Returns either the index at which the element is found, and true
     /// or the index where the element should be, and false.
 
@@ -242,6 +321,95 @@ impl<'data> BigVec<'data> {
         }
     }
 
+    /// Remove the element at `index`, shifting all following elements down
+    /// to fill the gap
+    pub fn remove<T: Pack>(&mut self, index: usize) -> Result<(), ProgramError> {
+        let vec_len = self.len();
+        if index >= vec_len as usize {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        let start = VEC_SIZE_BYTES + index * T::LEN;
+        let end = VEC_SIZE_BYTES + vec_len as usize * T::LEN;
+        let bytes_to_shift = end - start - T::LEN;
+        if bytes_to_shift > 0 {
+            unsafe {
+                sol_memmove(
+                    self.data[start..].as_mut_ptr(),
+                    self.data[start + T::LEN..].as_mut_ptr(),
+                    bytes_to_shift,
+                );
+            }
+        }
+
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        (vec_len - 1).serialize(&mut vec_len_ref)?;
+        Ok(())
+    }
+
+    /// Remove the element at `index` by overwriting it with the last
+    /// element, then shrinking the vec by one. O(1), but does not preserve
+    /// the order of the remaining elements.
+    pub fn swap_remove<T: Pack>(&mut self, index: usize) -> Result<(), ProgramError> {
+        let vec_len = self.len();
+        if index >= vec_len as usize {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        let last_index = vec_len as usize - 1;
+        if index != last_index {
+            let start = VEC_SIZE_BYTES + index * T::LEN;
+            let last_start = VEC_SIZE_BYTES + last_index * T::LEN;
+            unsafe {
+                sol_memmove(
+                    self.data[start..start + T::LEN].as_mut_ptr(),
+                    self.data[last_start..last_start + T::LEN].as_mut_ptr(),
+                    T::LEN,
+                );
+            }
+        }
+
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        (vec_len - 1).serialize(&mut vec_len_ref)?;
+        Ok(())
+    }
+
+    /// Remove and return the last element, or `None` if the vec is empty
+    pub fn pop<T: Pack>(&mut self) -> Option<T> {
+        let vec_len = self.len();
+        if vec_len == 0 {
+            return None;
+        }
+
+        let index = vec_len as usize - 1;
+        let start = VEC_SIZE_BYTES + index * T::LEN;
+        let end = start + T::LEN;
+        let element = T::unpack_from_slice(&self.data[start..end]).ok()?;
+
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        (vec_len - 1).serialize(&mut vec_len_ref).ok()?;
+        Some(element)
+    }
+
+    /// Shorten the vec to `new_len` elements, dropping the rest. No-op if
+    /// `new_len` is greater than or equal to the current length.
+    pub fn truncate<T: Pack>(&mut self, new_len: usize) -> Result<(), ProgramError> {
+        if new_len >= self.len() as usize {
+            return Ok(());
+        }
+
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        (new_len as u32).serialize(&mut vec_len_ref)?;
+        Ok(())
+    }
+
+    /// Remove all elements
+    pub fn clear(&mut self) -> Result<(), ProgramError> {
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        0u32.serialize(&mut vec_len_ref)?;
+        Ok(())
+    }
+
     /// Find matching data in the array
     fn get<T: Pack>(&self, index: usize) -> Option<&T> {
         let len = self.len() as usize;
@@ -265,6 +433,182 @@ impl<'data> BigVec<'data> {
         }
         None
     }
+
+    /// Zero-copy lookup of the element at `index`, see the module docs for
+    /// the layout invariant `T` must uphold
+    pub fn get_pod<T: Pod>(&self, index: usize) -> Option<&T> {
+        let len = self.len() as usize;
+        if index < len {
+            let elem_len = size_of::<T>();
+            let start = VEC_SIZE_BYTES + index * elem_len;
+            let end = start + elem_len;
+            let slice = self.data.get(start..end)?;
+            bytemuck::try_from_bytes(slice).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Zero-copy mutable lookup of the element at `index`, see the module
+    /// docs for the layout invariant `T` must uphold
+    pub fn get_mut_pod<T: Pod>(&mut self, index: usize) -> Option<&mut T> {
+        let len = self.len() as usize;
+        if index < len {
+            let elem_len = size_of::<T>();
+            let start = VEC_SIZE_BYTES + index * elem_len;
+            let end = start + elem_len;
+            let slice = &mut self.data[start..end];
+            bytemuck::try_from_bytes_mut(slice).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Zero-copy version of `deserialize_mut_slice`, see the module docs for
+    /// the layout invariant `T` must uphold
+    pub fn deserialize_mut_slice_pod<T: Pod>(
+        &mut self,
+        skip: usize,
+        len: usize,
+    ) -> Result<Vec<&'data mut T>, ProgramError> {
+        let vec_len = self.len();
+        if skip + len > vec_len as usize {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        let elem_len = size_of::<T>();
+        let start_index = VEC_SIZE_BYTES.saturating_add(skip.saturating_mul(elem_len));
+        let end_index = start_index.saturating_add(len.saturating_mul(elem_len));
+        let mut deserialized = vec![];
+        for slice in self.data[start_index..end_index].chunks_exact_mut(elem_len) {
+            let element: &mut T = bytemuck::try_from_bytes_mut(slice)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            // safety: extends the borrow from this call's `&mut self` to
+            // `'data`, the lifetime of the underlying account buffer; the
+            // cast itself was already validated by bytemuck
+            deserialized.push(unsafe { &mut *(element as *mut T) });
+        }
+        Ok(deserialized)
+    }
+
+    /// Zero-copy version of `push`, avoids the Borsh round-trip through
+    /// `pack_into_slice`
+    pub fn push_pod<T: Pod>(&mut self, element: T) -> Result<(), ProgramError> {
+        let elem_len = size_of::<T>();
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        let mut vec_len = u32::try_from_slice(vec_len_ref)?;
+
+        let start_index = VEC_SIZE_BYTES + vec_len as usize * elem_len;
+        let end_index = start_index + elem_len;
+
+        vec_len += 1;
+        vec_len.serialize(&mut vec_len_ref)?;
+
+        if self.data.len() < end_index {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let dst: &mut T = bytemuck::try_from_bytes_mut(&mut self.data[start_index..end_index])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        *dst = element;
+        Ok(())
+    }
+
+    /// Zero-copy version of `insert_in_order`, avoids the Borsh round-trip
+    /// through `pack_into_slice`
+    pub fn insert_in_order_pod<T: Pod + Ord + std::fmt::Debug>(
+        &mut self,
+        element: &T,
+    ) -> Result<(), ProgramError> {
+        let (index, is_found) = self.binary_search_pod(element);
+        if is_found {
+            msg!(
+                "Cannot insert existing element. Found existing at vec index {}",
+                index
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let elem_len = size_of::<T>();
+        let buffer_len = self.data.len();
+        let mut vec_len_ref = &mut self.data[0..VEC_SIZE_BYTES];
+        let mut vec_len = u32::try_from_slice(vec_len_ref)?;
+        vec_len += 1;
+
+        if (VEC_SIZE_BYTES + vec_len as usize * elem_len) > buffer_len {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        vec_len.serialize(&mut vec_len_ref)?;
+
+        let start = VEC_SIZE_BYTES + index * elem_len;
+        let bytes_to_shift = (vec_len as usize - 1 - index) * elem_len;
+
+        unsafe {
+            sol_memmove(
+                self.data[start + elem_len..].as_mut_ptr(),
+                self.data[start..].as_mut_ptr(),
+                bytes_to_shift,
+            );
+        }
+
+        let dst: &mut T = bytemuck::try_from_bytes_mut(&mut self.data[start..start + elem_len])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        *dst = *element;
+
+        Ok(())
+    }
+
+    /// Get an iterator for the Pod type provided
+    pub fn iter_pod<'vec, T: Pod>(&'vec self) -> IterPod<'data, 'vec, T> {
+        IterPod {
+            len: self.len() as usize,
+            current: 0,
+            current_index: VEC_SIZE_BYTES,
+            inner: self,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Get a mutable iterator for the Pod type provided
+    pub fn iter_mut_pod<'vec, T: Pod>(&'vec mut self) -> IterMutPod<'data, 'vec, T> {
+        IterMutPod {
+            len: self.len() as usize,
+            current: 0,
+            current_index: VEC_SIZE_BYTES,
+            inner: self,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns either the index at which the element is found, and true
+    /// or the index where the element should be, and false.
+    ///
+    /// The returned index is in the range [0, len] inclusive
+    fn binary_search_pod<T: Pod + Ord>(&self, element: &T) -> (usize, bool) {
+        let len = self.len() as usize;
+        if len == 0 {
+            return (0, false);
+        }
+        let (mut min, mut max) = (0, len - 1);
+
+        while min <= max {
+            let mid = (max - min) / 2 + min;
+            if let Some(elem_at_index) = self.get_pod::<T>(mid) {
+                if *elem_at_index == *element {
+                    return (mid, true);
+                } else if *elem_at_index < *element {
+                    min = mid + 1;
+                } else {
+                    if mid == 0 {
+                        return (0, false);
+                    }
+                    max = mid - 1;
+                }
+            } else {
+                return (0, false);
+            }
+        }
+        (min, false)
+    }
 }
 
 /// Iterator wrapper over a BigVec
@@ -321,9 +665,71 @@ impl<'data, 'vec, T: Pack + 'data> Iterator for IterMut<'data, 'vec, T> {
     }
 }
 
+/// Zero-copy iterator wrapper over a BigVec, see the module docs for the
+/// layout invariant `T` must uphold
+pub struct IterPod<'data, 'vec, T> {
+    len: usize,
+    current: usize,
+    current_index: usize,
+    inner: &'vec BigVec<'data>,
+    phantom: PhantomData<T>,
+}
+
+impl<'data, 'vec, T: Pod + 'data> Iterator for IterPod<'data, 'vec, T> {
+    type Item = &'data T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.len {
+            None
+        } else {
+            let end_index = self.current_index + size_of::<T>();
+            let slice = &self.inner.data[self.current_index..end_index];
+            let element: &T = bytemuck::try_from_bytes(slice).ok()?;
+            // safety: extends the borrow from this slice to 'data, the
+            // lifetime of the underlying account buffer; the cast itself
+            // was already validated by bytemuck
+            let value = Some(unsafe { &*(element as *const T) });
+            self.current += 1;
+            self.current_index = end_index;
+            value
+        }
+    }
+}
+
+/// Zero-copy mutable iterator wrapper over a BigVec, see the module docs for
+/// the layout invariant `T` must uphold
+pub struct IterMutPod<'data, 'vec, T> {
+    len: usize,
+    current: usize,
+    current_index: usize,
+    inner: &'vec mut BigVec<'data>,
+    phantom: PhantomData<T>,
+}
+
+impl<'data, 'vec, T: Pod + 'data> Iterator for IterMutPod<'data, 'vec, T> {
+    type Item = &'data mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current == self.len {
+            None
+        } else {
+            let end_index = self.current_index + size_of::<T>();
+            let slice = &self.inner.data[self.current_index..end_index];
+            let element: &T = bytemuck::try_from_bytes(slice).ok()?;
+            // safety: extends the borrow to 'data and widens to `&mut`; the
+            // cast itself was already validated by bytemuck, and exclusive
+            // access is guaranteed since each index is only ever yielded once
+            let value = Some(unsafe { &mut *(element as *const T as *mut T) });
+            self.current += 1;
+            self.current_index = end_index;
+            value
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use {super::*, solana_program::program_pack::Sealed};
+    use {super::*, bytemuck::Zeroable, solana_program::program_pack::Sealed};
 
     #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
     struct TestStruct {
@@ -351,6 +757,20 @@ mod tests {
         }
     }
 
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Pod, Zeroable)]
+    struct TestStructPod {
+        value: [u8; 8],
+    }
+
+    impl TestStructPod {
+        fn new(value: u64) -> Self {
+            Self {
+                value: value.to_le_bytes(),
+            }
+        }
+    }
+
     fn from_slice<'data, 'other>(data: &'data mut [u8], vec: &'other [u64]) -> BigVec<'data> {
         let mut big_vec = BigVec { data };
         for element in vec {
@@ -462,4 +882,190 @@ mod tests {
             ProgramError::AccountDataTooSmall
         );
     }
+
+    #[test]
+    fn remove() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = from_slice(&mut data, &[1, 2, 3, 4]);
+        v.remove::<TestStruct>(1).unwrap();
+        check_big_vec_eq(&v, &[1, 3, 4]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(
+            v.remove::<TestStruct>(3).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn swap_remove() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = from_slice(&mut data, &[1, 2, 3, 4]);
+        v.swap_remove::<TestStruct>(0).unwrap();
+        check_big_vec_eq(&v, &[4, 2, 3]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(
+            v.swap_remove::<TestStruct>(3).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn pop() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = from_slice(&mut data, &[1, 2, 3]);
+        assert_eq!(v.pop::<TestStruct>(), Some(TestStruct::new(3)));
+        assert_eq!(v.pop::<TestStruct>(), Some(TestStruct::new(2)));
+        assert_eq!(v.pop::<TestStruct>(), Some(TestStruct::new(1)));
+        assert_eq!(v.pop::<TestStruct>(), None);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = from_slice(&mut data, &[1, 2, 3, 4]);
+        v.truncate::<TestStruct>(2).unwrap();
+        check_big_vec_eq(&v, &[1, 2]);
+        assert_eq!(v.len(), 2);
+        v.truncate::<TestStruct>(5).unwrap();
+        assert_eq!(v.len(), 2);
+    }
+
+    #[test]
+    fn clear() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = from_slice(&mut data, &[1, 2, 3, 4]);
+        v.clear().unwrap();
+        assert_eq!(v.len(), 0);
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn remove_value() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut array = [6, 2, 8, 4];
+        let mut v = from_slice_in_order(&mut data, &array);
+        assert!(v.remove_value::<TestStruct>(&TestStruct::new(2)).unwrap());
+        assert!(!v.remove_value::<TestStruct>(&TestStruct::new(2)).unwrap());
+        array.sort();
+        let remaining: Vec<u64> = array.into_iter().filter(|x| *x != 2).collect();
+        check_big_vec_eq(&v, &remaining);
+    }
+
+    #[test]
+    fn binary_search_by() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let v = from_slice(&mut data, &[1, 2, 3, 4]);
+        assert_eq!(
+            v.binary_search_by::<TestStruct>(|elem| elem.value.cmp(&3)),
+            Ok(2)
+        );
+        assert_eq!(
+            v.binary_search_by::<TestStruct>(|elem| elem.value.cmp(&5)),
+            Err(4)
+        );
+        assert_eq!(
+            v.binary_search_by::<TestStruct>(|elem| elem.value.cmp(&0)),
+            Err(0)
+        );
+    }
+
+    #[test]
+    fn partition_point() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let v = from_slice(&mut data, &[1, 2, 3, 4]);
+        assert_eq!(v.partition_point::<TestStruct>(|elem| elem.value < 3), 2);
+        assert_eq!(v.partition_point::<TestStruct>(|_elem| true), 4);
+        assert_eq!(v.partition_point::<TestStruct>(|_elem| false), 0);
+    }
+
+    #[test]
+    fn range() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let v = from_slice(&mut data, &[1, 2, 3, 4]);
+        let values: Vec<u64> = v
+            .range::<TestStruct>(1, 3)
+            .unwrap()
+            .map(|elem| elem.value)
+            .collect();
+        assert_eq!(values, vec![2, 3]);
+        assert_eq!(
+            v.range::<TestStruct>(0, 5).err(),
+            Some(ProgramError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn push_pod() {
+        let mut data = [0u8; 4 + 8 * 3];
+        let mut v = BigVec { data: &mut data };
+        v.push_pod(TestStructPod::new(1)).unwrap();
+        v.push_pod(TestStructPod::new(2)).unwrap();
+        v.push_pod(TestStructPod::new(3)).unwrap();
+        assert_eq!(
+            v.iter_pod::<TestStructPod>().collect::<Vec<_>>(),
+            vec![
+                &TestStructPod::new(1),
+                &TestStructPod::new(2),
+                &TestStructPod::new(3),
+            ]
+        );
+        assert_eq!(
+            v.push_pod(TestStructPod::new(4)).unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
+
+    #[test]
+    fn check_in_order_pod() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = BigVec { data: &mut data };
+        let mut array = [6, 2, 8, 4];
+        for element in array.iter() {
+            v.insert_in_order_pod(&TestStructPod::new(*element))
+                .unwrap();
+        }
+        array.sort_unstable();
+
+        for (i, item) in array.iter().enumerate() {
+            assert_eq!(
+                *v.get_pod::<TestStructPod>(i).unwrap(),
+                TestStructPod::new(*item)
+            );
+        }
+        assert_eq!(
+            v.insert_in_order_pod(&TestStructPod::new(6)).unwrap_err(),
+            ProgramError::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn deserialize_mut_slice_pod() {
+        let mut data = [0u8; 4 + 8 * 4];
+        let mut v = BigVec { data: &mut data };
+        for element in [1, 2, 3, 4] {
+            v.push_pod(TestStructPod::new(element)).unwrap();
+        }
+        let mut slice = v.deserialize_mut_slice_pod::<TestStructPod>(1, 2).unwrap();
+        slice[0].value = 10u64.to_le_bytes();
+        slice[1].value = 11u64.to_le_bytes();
+        assert_eq!(
+            v.iter_pod::<TestStructPod>().collect::<Vec<_>>(),
+            vec![
+                &TestStructPod::new(1),
+                &TestStructPod::new(10),
+                &TestStructPod::new(11),
+                &TestStructPod::new(4),
+            ]
+        );
+        assert_eq!(
+            v.deserialize_mut_slice_pod::<TestStructPod>(1, 4)
+                .unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+        assert_eq!(
+            v.deserialize_mut_slice_pod::<TestStructPod>(4, 1)
+                .unwrap_err(),
+            ProgramError::AccountDataTooSmall
+        );
+    }
 }
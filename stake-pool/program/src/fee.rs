@@ -58,16 +58,77 @@ impl Fee {
 
     /// Applies the Fee's rates to a given amount, `amt`
     /// returning the amount to be subtracted from it as fees
+    ///
+    /// Rounds down, equivalent to `apply_rounding(amt, Rounding::Down)`
     pub fn apply(&self, amt: u64) -> u64 {
+        self.apply_rounding(amt, Rounding::Down)
+    }
+
+    /// Applies the Fee's rates to a given amount, `amt`, rounding the result
+    /// in the given direction, and returning the amount to be subtracted
+    /// from it as fees
+    pub fn apply_rounding(&self, amt: u64, rounding: Rounding) -> u64 {
         let amt_expanded = amt as u128;
         let numerator_expanded = self.numerator as u128;
         let denominator_expanded = self.denominator as u128;
         // overflow safety: both amt_expanded and numerator_expanded are u64
         // div safety: denominator != 0
-        let fees = amt_expanded * numerator_expanded / denominator_expanded;
+        let fees = match rounding {
+            Rounding::Down => amt_expanded * numerator_expanded / denominator_expanded,
+            Rounding::Up => {
+                (amt_expanded * numerator_expanded + denominator_expanded - 1)
+                    / denominator_expanded
+            }
+        };
         // as safety: numerator / denominator <= 1.  fees <= amt_expanded <= u64::MAX
         fees as u64
     }
+
+    /// Creates a new Fee that represents the sum of `self` and `rhs`, even
+    /// when they don't share a denominator. Useful for composing several
+    /// fees, e.g. an epoch fee layered on top of a management fee, into a
+    /// single Fee to `apply` once instead of applying each in sequence and
+    /// compounding rounding loss.
+    ///
+    /// Clamps the result to 1/1 if the sum would otherwise exceed 100%,
+    /// since fees can't exceed the full amount.
+    pub fn try_add(self, rhs: Self) -> Result<Self, StakePoolError> {
+        // multiplication overflow safety:
+        // numerator <= denominator <= MAX_FEE_PRECISION < sqrt(u64::max),
+        // so each cross-product, and their sum, stays well under u64::MAX
+        let mut numerator = self.numerator * rhs.denominator + rhs.numerator * self.denominator;
+        // denominator safety: denominator > 0, since both > 0
+        let mut denominator = self.denominator * rhs.denominator;
+
+        if denominator > MAX_FEE_PRECISION {
+            // division safety: MAX_FEE_PRECISION > 0
+            let divisor = max(2, denominator / MAX_FEE_PRECISION);
+            // division safety: divisor > 0
+            // Note: results in loss of precision for numerator if not
+            // divisible by divisor, same as in Mul
+            numerator /= divisor;
+            denominator /= divisor;
+        }
+
+        // fees can't exceed 100%, clamp instead of erroring
+        if numerator > denominator {
+            numerator = denominator;
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+/// Direction to round a fee calculation in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero, favoring the amount being charged the fee
+    Down,
+    /// Round away from zero, favoring the party receiving the fee
+    Up,
 }
 
 impl PartialEq for Fee {
@@ -125,3 +186,47 @@ impl ops::Mul for Fee {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_rounding_down_matches_apply() {
+        let fee = Fee::try_new(1, 3).unwrap();
+        assert_eq!(fee.apply_rounding(1, Rounding::Down), 0);
+        assert_eq!(fee.apply_rounding(1, Rounding::Down), fee.apply(1));
+    }
+
+    #[test]
+    fn apply_rounding_up_does_not_zero_out_small_fees() {
+        let fee = Fee::try_new(1, 3).unwrap();
+        assert_eq!(fee.apply_rounding(1, Rounding::Up), 1);
+        assert_eq!(fee.apply_rounding(2, Rounding::Up), 1);
+        assert_eq!(fee.apply_rounding(3, Rounding::Up), 1);
+        assert_eq!(fee.apply_rounding(4, Rounding::Up), 2);
+    }
+
+    #[test]
+    fn try_add_same_denominator() {
+        let a = Fee::try_new(1, 10).unwrap();
+        let b = Fee::try_new(2, 10).unwrap();
+        assert_eq!(a.try_add(b).unwrap(), Fee::try_new(3, 10).unwrap());
+    }
+
+    #[test]
+    fn try_add_needs_reduction() {
+        // denominators multiply out to 2_000_000_000, which exceeds
+        // MAX_FEE_PRECISION and forces the divisor-reduction branch
+        let a = Fee::try_new(1, 1_000_000).unwrap();
+        let b = Fee::try_new(1, 2_000).unwrap();
+        assert_eq!(a.try_add(b).unwrap(), Fee::try_new(501, 1_000_000).unwrap());
+    }
+
+    #[test]
+    fn try_add_clamps_to_100_percent() {
+        let a = Fee::try_new(8, 10).unwrap();
+        let b = Fee::try_new(5, 10).unwrap();
+        assert_eq!(a.try_add(b).unwrap(), Fee::try_new(1, 1).unwrap());
+    }
+}